@@ -0,0 +1,84 @@
+//! Double-scalar multiplication (a.k.a. "Shamir's trick" / "lincomb").
+//!
+//! ECDSA verification needs `u1·G + u2·Q` rather than either term alone, so
+//! computing them as two independent scalar multiplications and adding the
+//! results wastes half of the work: the two chains of doublings can be
+//! shared. This module implements the interleaved double-and-add variant
+//! used by the `k256` verifier.
+
+use crate::{ProjectivePoint, Scalar};
+use elliptic_curve::group::Group;
+
+impl ProjectivePoint {
+    /// Compute `x·A + y·B` in constant "shape" (same doubling chain for both
+    /// scalars), which is roughly 2x faster than two independent scalar
+    /// multiplications followed by an addition.
+    pub fn lincomb(a: &ProjectivePoint, x: &Scalar, b: &ProjectivePoint, y: &Scalar) -> Self {
+        let table = LincombTable::new(a, b);
+        let x_bytes = x.to_bytes();
+        let y_bytes = y.to_bytes();
+
+        let mut acc = ProjectivePoint::identity();
+
+        for bit in 0..256 {
+            acc = acc.double();
+
+            let xi = bit_at(&x_bytes, bit) as usize;
+            let yi = bit_at(&y_bytes, bit) as usize;
+
+            acc += table.select(xi, yi);
+        }
+
+        acc
+    }
+}
+
+/// Extract the `bit`-th most-significant bit of a big-endian 32-byte scalar.
+fn bit_at(bytes: &[u8], bit: usize) -> bool {
+    let byte = bytes[bit / 8];
+    let shift = 7 - (bit % 8);
+    (byte >> shift) & 1 == 1
+}
+
+/// Precomputed `{O, A, B, A+B}` table indexed by the joint bit pair `(xi, yi)`.
+struct LincombTable {
+    points: [ProjectivePoint; 4],
+}
+
+impl LincombTable {
+    fn new(a: &ProjectivePoint, b: &ProjectivePoint) -> Self {
+        Self {
+            points: [
+                ProjectivePoint::identity(),
+                *a,
+                *b,
+                *a + b,
+            ],
+        }
+    }
+
+    /// Select the table entry for the bit pair `(xi, yi)`.
+    fn select(&self, xi: usize, yi: usize) -> ProjectivePoint {
+        self.points[xi | (yi << 1)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scalar;
+
+    #[test]
+    fn lincomb_matches_naive_double_mul() {
+        let g = ProjectivePoint::generator();
+        let q = g * Scalar::from(42u64);
+
+        let u1 = Scalar::from(7u64);
+        let u2 = Scalar::from(13u64);
+
+        let expected = (g * u1) + (q * u2);
+        let actual = ProjectivePoint::lincomb(&g, &u1, &q, &u2);
+
+        assert_eq!(expected, actual);
+    }
+}
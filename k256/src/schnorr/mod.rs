@@ -0,0 +1,33 @@
+//! BIP-340 Schnorr signatures over secp256k1 ("Taproot" signatures).
+//!
+//! This is a sibling of the [`ecdsa`][`super::ecdsa`] module: where ECDSA
+//! keys carry an explicit Y-coordinate parity bit in their SEC1 encoding, BIP-340
+//! fixes it as "even" and only ever publishes the X-only public key,
+//! trimming both keys and signatures down to 32 and 64 bytes respectively.
+
+mod verify;
+
+pub use verify::{Signature, VerifyingKey};
+
+use elliptic_curve::{consts::U32, generic_array::GenericArray};
+use sha2::{Digest, Sha256};
+
+/// Size of a BIP-340 x-only public key in bytes.
+pub const PUBLIC_KEY_SIZE: usize = 32;
+
+/// Size of a BIP-340 Schnorr signature in bytes.
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// Compute `tagged_hash(tag, data) = SHA256(SHA256(tag) || SHA256(tag) || data)`
+/// as specified by BIP-340.
+pub(crate) fn tagged_hash(tag: &[u8], msgs: &[&[u8]]) -> GenericArray<u8, U32> {
+    let tag_hash = Sha256::digest(tag);
+
+    let mut digest = Sha256::new().chain(tag_hash).chain(tag_hash);
+
+    for msg in msgs {
+        digest = digest.chain(msg);
+    }
+
+    digest.finalize()
+}